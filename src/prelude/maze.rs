@@ -9,12 +9,25 @@ use petgraph::graphmap::GraphMap;
 #[cfg(feature = "std")]
 use petgraph::stable_graph::DefaultIx;
 use petgraph::Undirected;
-use petgraph::lib::Vec;
+use petgraph::lib::{BTreeMap, Vec, VecDeque};
 
 use crate::prelude::*;
 
 pub(crate) type MazeGraph = GraphMap<Coordinates, (), Undirected>;
 
+/// A single cell of a [`Maze::to_grid`] tile grid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Tile {
+    /// An impassable wall cell.
+    Wall,
+    /// A walkable floor cell.
+    Floor,
+    /// A walkable cell inside the floor block of the maze's start field.
+    Start,
+    /// A walkable cell inside the floor block of the maze's goal field.
+    Goal,
+}
+
 /// A collection of [`Field`]s with passages between them.
 ///
 /// Use one of the provided [`Generator`]s to create an instance of this type.
@@ -75,6 +88,171 @@ impl Maze {
             && coordinates.y >= 0
             && coordinates.y < self.size.1
     }
+
+    /// Computes the geodesic distance, in passage hops, from `origin` to every cell reachable
+    /// from it.
+    ///
+    /// Since every passage edge has unit cost, this is a plain breadth-first flood of the
+    /// passage graph.
+    pub fn distances_from(&self, origin: &Coordinates) -> BTreeMap<Coordinates, u32> {
+        let mut distances = BTreeMap::new();
+        let mut queue = VecDeque::new();
+
+        distances.insert(*origin, 0);
+        queue.push_back(*origin);
+
+        while let Some(current) = queue.pop_front() {
+            let dist = distances[&current];
+            for dir in Direction::all().iter() {
+                let neighbor = current.next(dir);
+                if distances.contains_key(&neighbor) || !self.graph.contains_edge(current, neighbor)
+                {
+                    continue;
+                }
+                distances.insert(neighbor, dist + 1);
+                queue.push_back(neighbor);
+            }
+        }
+
+        distances
+    }
+
+    /// Returns the cell that is farthest, in passage hops, from `origin`.
+    ///
+    /// Handy for relocating [`Maze::goal`] to the hardest-to-reach cell after generation.
+    pub fn farthest_cell_from(&self, origin: &Coordinates) -> Coordinates {
+        self.distances_from(origin)
+            .into_iter()
+            .max_by_key(|(_, dist)| *dist)
+            .map_or(*origin, |(coordinates, _)| coordinates)
+    }
+
+    /// Computes the shortest passage-connected path from [`Maze::start`] to [`Maze::goal`], if
+    /// one exists.
+    ///
+    /// This performs a breadth-first search over the passage graph, recording a predecessor for
+    /// every newly-reached cell, then walks the predecessors back from [`Maze::goal`] to
+    /// [`Maze::start`] and reverses the result.
+    pub fn solve(&self) -> Option<Vec<Coordinates>> {
+        let mut predecessors: BTreeMap<Coordinates, Coordinates> = BTreeMap::new();
+        let mut visited: BTreeMap<Coordinates, u32> = BTreeMap::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(self.start, 0);
+        queue.push_back(self.start);
+
+        while let Some(current) = queue.pop_front() {
+            if current == self.goal {
+                break;
+            }
+
+            let dist = visited[&current];
+            for dir in Direction::all().iter() {
+                let neighbor = current.next(dir);
+                if visited.contains_key(&neighbor) || !self.graph.contains_edge(current, neighbor)
+                {
+                    continue;
+                }
+                visited.insert(neighbor, dist + 1);
+                predecessors.insert(neighbor, current);
+                queue.push_back(neighbor);
+            }
+        }
+
+        if !visited.contains_key(&self.goal) {
+            return None;
+        }
+
+        let mut path = Vec::new();
+        let mut current = self.goal;
+        path.push(current);
+        while current != self.start {
+            current = *predecessors.get(&current)?;
+            path.push(current);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Renders the maze as a thick-walled tile grid, suitable for tile engines and pathfinding
+    /// crates that expect a solid occupancy grid rather than a thin-wall line graph.
+    ///
+    /// Each field becomes a `cell_size`×`cell_size` block of floor tiles, separated by
+    /// `wall_thickness` rows/columns of wall tiles, with openings carved only where
+    /// [`Field::has_passage`] reports a passage. The whole grid is enclosed by an outer wall
+    /// border whose thickness is also `wall_thickness` — pass at least `1` if you want that
+    /// border to actually render; `0` produces a flush grid with no walls anywhere, including no
+    /// border.
+    pub fn to_grid(&self, cell_size: usize, wall_thickness: usize) -> Vec<Vec<Tile>> {
+        let stride_x = cell_size + wall_thickness;
+        let stride_y = cell_size + wall_thickness;
+        let width = wall_thickness + self.size.0 as usize * stride_x;
+        let height = wall_thickness + self.size.1 as usize * stride_y;
+
+        let mut grid = Vec::new();
+        for _ in 0..height {
+            let mut row = Vec::new();
+            for _ in 0..width {
+                row.push(Tile::Wall);
+            }
+            grid.push(row);
+        }
+
+        for iy in 0..self.size.1 {
+            for ix in 0..self.size.0 {
+                let coordinates = Coordinates::new(ix, iy);
+                let field = match self.get_field(&coordinates) {
+                    Some(field) => field,
+                    None => continue,
+                };
+
+                let floor_tile = match field.field_type {
+                    FieldType::Start => Tile::Start,
+                    FieldType::Goal => Tile::Goal,
+                    FieldType::Normal => Tile::Floor,
+                };
+
+                let ox = wall_thickness + ix as usize * stride_x;
+                let oy = wall_thickness + iy as usize * stride_y;
+
+                for row in grid.iter_mut().skip(oy).take(cell_size) {
+                    for tile in row.iter_mut().skip(ox).take(cell_size) {
+                        *tile = floor_tile;
+                    }
+                }
+
+                if field.has_passage(&Direction::North) {
+                    for row in grid
+                        .iter_mut()
+                        .skip(oy.saturating_sub(wall_thickness))
+                        .take(wall_thickness)
+                    {
+                        for tile in row.iter_mut().skip(ox).take(cell_size) {
+                            *tile = Tile::Floor;
+                        }
+                    }
+                }
+
+                if field.has_passage(&Direction::West) {
+                    for row in grid.iter_mut().skip(oy).take(cell_size) {
+                        for tile in row
+                            .iter_mut()
+                            .skip(ox.saturating_sub(wall_thickness))
+                            .take(wall_thickness)
+                        {
+                            *tile = Tile::Floor;
+                        }
+                    }
+                }
+
+                // South/East openings are carved by the neighboring field's own North/West
+                // opening, so there is nothing further to do for them here.
+            }
+        }
+
+        grid
+    }
 }
 
 #[cfg(feature = "std")]
@@ -126,6 +304,95 @@ impl std::fmt::Debug for Maze {
     }
 }
 
+/// Options controlling the look of [`Maze::to_svg`]'s output.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct SvgOptions {
+    /// Padding, in pixels, around the maze.
+    pub padding: i32,
+    /// Radius, in pixels, of the start/goal markers.
+    pub markersize: i32,
+    /// Height of the maze image in pixels, excluding padding; width is derived from it using the
+    /// maze's aspect ratio. `None` derives a height from `padding` and the maze's own height.
+    pub height: Option<i32>,
+    /// Stroke color of wall segments; in [`SvgOptions::inverted`] mode this instead becomes the
+    /// fill color of the cave "rock" background.
+    pub strokecol: String,
+    /// Stroke width, in pixels, of wall segments; in [`SvgOptions::inverted`] mode this doubles
+    /// as the width of the rock margin left on each side of a field that has no passage.
+    pub strokewidth: i32,
+    /// Fill/stroke color of the start marker.
+    pub startcol: String,
+    /// Fill/stroke color of the goal marker.
+    pub goalcol: String,
+    /// Fill color of the carved-out passages when [`SvgOptions::inverted`] is set. Unused
+    /// otherwise.
+    pub backgroundcol: String,
+    /// Amplitude, in pixels, of the deterministic jitter applied to wall-segment endpoints. `0`
+    /// (the default) disables distortion and renders a crisp rectilinear grid.
+    pub distort: i32,
+    /// Render passages as filled areas instead of drawing wall lines, producing a cave-like
+    /// negative of the usual thin-wall rendering: the maze is filled with
+    /// [`SvgOptions::strokecol`] as rock, each field's floor is drawn over it in
+    /// [`SvgOptions::backgroundcol`], and the walls show as the rock left between the floors.
+    pub inverted: bool,
+}
+
+#[cfg(feature = "std")]
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            padding: 10,
+            markersize: 6,
+            height: None,
+            strokecol: "black".into(),
+            strokewidth: 2,
+            startcol: "green".into(),
+            goalcol: "red".into(),
+            backgroundcol: "white".into(),
+            distort: 0,
+            inverted: false,
+        }
+    }
+}
+
+/// Deterministically perturbs a wall-segment endpoint by up to `amplitude` pixels along each
+/// axis, seeded from the endpoint's own (undistorted) coordinates. Segments that share a corner
+/// pass in the same `(x, y)` and therefore receive the same offset, so the walls stay joined.
+#[cfg(feature = "std")]
+fn distort_point(amplitude: i32, x: i32, y: i32) -> (i32, i32) {
+    if amplitude <= 0 {
+        return (x, y);
+    }
+
+    let seed = (x as i64)
+        .wrapping_mul(73_856_093)
+        .wrapping_add((y as i64).wrapping_mul(19_349_663));
+    let range = 2 * amplitude + 1;
+    let dx = (seed.wrapping_mul(2_654_435_761) as i32).rem_euclid(range) - amplitude;
+    let dy = (seed.wrapping_mul(40_503) as i32).rem_euclid(range) - amplitude;
+
+    (x + dx, y + dy)
+}
+
+/// Writes a single (possibly [`SvgOptions::distort`]ed) wall segment to `svg`.
+#[cfg(feature = "std")]
+fn write_wall_line(
+    svg: &mut String,
+    (x1, y1): (i32, i32),
+    (x2, y2): (i32, i32),
+    distort_amplitude: i32,
+) -> Result<()> {
+    let (x1, y1) = distort_point(distort_amplitude, x1, y1);
+    let (x2, y2) = distort_point(distort_amplitude, x2, y2);
+    writeln!(
+        svg,
+        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>",
+        x1, y1, x2, y2
+    )?;
+    Ok(())
+}
+
 impl Maze {
     /// Generate an SVG version of the maze, returned as a String which you can then write to a file or use directly
     #[cfg(feature = "std")]
@@ -179,6 +446,19 @@ impl Maze {
         writeln!(svg, "    stroke-width: {};\n}}", svgoptions.strokewidth)?;
         writeln!(svg, "]]></style>\n</defs>")?;
 
+        let inverted = svgoptions.inverted;
+        let distort_amplitude = svgoptions.distort;
+
+        if inverted {
+            // Fill the whole maze with "rock" first; passages are then carved out of it below,
+            // producing a cave-like negative of the usual thin wall lines.
+            writeln!(
+                svg,
+                "<rect x=\"0\" y=\"0\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                width, height, svgoptions.strokecol
+            )?;
+        }
+
         for iy in 0..self.size.1 {
             // print top passage
             for ix in 0..self.size.0 {
@@ -190,16 +470,12 @@ impl Maze {
                     .has_passage(&Direction::North)
                 {
                     // Do nothing. This code structure keeps the SVG output aligned with the original text debug output
-                } else {
+                } else if !inverted {
                     x1 = ix * scx;
                     y1 = iy * scy;
                     x2 = (ix + 1) * scx;
                     y2 = iy * scy;
-                    writeln!(
-                        svg,
-                        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>",
-                        x1, y1, x2, y2
-                    )?;
+                    write_wall_line(&mut svg, (x1, y1), (x2, y2), distort_amplitude)?;
                 }
             }
 
@@ -210,17 +486,47 @@ impl Maze {
                 })?;
                 if field.has_passage(&Direction::West) {
                     // Do nothing
-                } else {
+                } else if !inverted {
                     x1 = ix * scx;
                     y1 = iy * scy;
                     x2 = ix * scx;
                     y2 = (iy + 1) * scy;
+                    write_wall_line(&mut svg, (x1, y1), (x2, y2), distort_amplitude)?;
+                }
+
+                if inverted {
+                    // Carve the floor for this field out of the "rock" background, leaving a
+                    // wall-width margin on every side that has no passage.
+                    let margin = svgoptions.strokewidth;
+                    let mut rx1 = ix * scx;
+                    let mut ry1 = iy * scy;
+                    let mut rx2 = (ix + 1) * scx;
+                    let mut ry2 = (iy + 1) * scy;
+
+                    if !field.has_passage(&Direction::North) {
+                        ry1 += margin;
+                    }
+                    if !field.has_passage(&Direction::South) {
+                        ry2 -= margin;
+                    }
+                    if !field.has_passage(&Direction::West) {
+                        rx1 += margin;
+                    }
+                    if !field.has_passage(&Direction::East) {
+                        rx2 -= margin;
+                    }
+
                     writeln!(
                         svg,
-                        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>",
-                        x1, y1, x2, y2
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                        rx1,
+                        ry1,
+                        rx2 - rx1,
+                        ry2 - ry1,
+                        svgoptions.backgroundcol
                     )?;
                 }
+
                 // Special cells
                 match field.field_type {
                     FieldType::Start => {
@@ -237,27 +543,21 @@ impl Maze {
                 };
             }
 
-            // print bottom border line
-            x1 = 0;
-            y1 = (self.size.1) * scy;
-            x2 = (self.size.0) * scx;
-            y2 = (self.size.1) * scy;
-            writeln!(
-                svg,
-                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>",
-                x1, y1, x2, y2
-            )?;
+            if !inverted {
+                // print bottom border line
+                x1 = 0;
+                y1 = (self.size.1) * scy;
+                x2 = (self.size.0) * scx;
+                y2 = (self.size.1) * scy;
+                write_wall_line(&mut svg, (x1, y1), (x2, y2), distort_amplitude)?;
 
-            // print right border line
-            x1 = (self.size.0) * scx;
-            y1 = 0;
-            x2 = (self.size.0) * scx;
-            y2 = (self.size.1) * scy;
-            writeln!(
-                svg,
-                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>",
-                x1, y1, x2, y2
-            )?;
+                // print right border line
+                x1 = (self.size.0) * scx;
+                y1 = 0;
+                x2 = (self.size.0) * scx;
+                y2 = (self.size.1) * scy;
+                write_wall_line(&mut svg, (x1, y1), (x2, y2), distort_amplitude)?;
+            }
         }
         writeln!(svg, "</svg>")?;
 
@@ -265,6 +565,113 @@ impl Maze {
     }
 }
 
+/// Drawing parameters for [`Maze::draw`].
+#[cfg(feature = "embedded_graphics")]
+#[derive(Debug, Copy, Clone)]
+pub struct MazeDrawStyle<C: embedded_graphics::pixelcolor::PixelColor> {
+    /// Color of the wall segments.
+    pub stroke_color: C,
+    /// Width, in pixels, of the wall segments.
+    pub stroke_width: u32,
+    /// Number of pixels a single maze cell occupies on the target.
+    pub scale: u32,
+    /// Color of the start marker.
+    pub start_color: C,
+    /// Color of the goal marker.
+    pub goal_color: C,
+}
+
+#[cfg(feature = "embedded_graphics")]
+impl Maze {
+    /// Draws the maze onto an `embedded-graphics` [`DrawTarget`], emitting each wall segment as a
+    /// [`Line`] and the start/goal fields as filled [`Circle`]s.
+    ///
+    /// This walks the same north/west-wall iteration as [`Maze::to_svg`], but writes straight to
+    /// the target instead of building a string, which keeps it usable on `no_std` targets such as
+    /// small e-paper/OLED panels driven from `riscv32`/ESP-class microcontrollers.
+    pub fn draw<D>(
+        &self,
+        target: &mut D,
+        style: MazeDrawStyle<D::Color>,
+    ) -> Result<(), D::Error>
+    where
+        D: embedded_graphics::draw_target::DrawTarget,
+    {
+        use embedded_graphics::{
+            prelude::*,
+            primitives::{Circle, Line, PrimitiveStyle},
+        };
+
+        let scale = style.scale as i32;
+        let wall_style = PrimitiveStyle::with_stroke(style.stroke_color, style.stroke_width);
+
+        for iy in 0..self.size.1 {
+            for ix in 0..self.size.0 {
+                let field = match self.get_field(&(ix, iy).into()) {
+                    Some(field) => field,
+                    None => continue,
+                };
+
+                if !field.has_passage(&Direction::North) {
+                    Line::new(
+                        Point::new(ix * scale, iy * scale),
+                        Point::new((ix + 1) * scale, iy * scale),
+                    )
+                    .into_styled(wall_style)
+                    .draw(target)?;
+                }
+
+                if !field.has_passage(&Direction::West) {
+                    Line::new(
+                        Point::new(ix * scale, iy * scale),
+                        Point::new(ix * scale, (iy + 1) * scale),
+                    )
+                    .into_styled(wall_style)
+                    .draw(target)?;
+                }
+
+                match field.field_type {
+                    FieldType::Start => {
+                        Circle::with_center(
+                            Point::new(ix * scale + scale / 2, iy * scale + scale / 2),
+                            style.scale / 2,
+                        )
+                        .into_styled(PrimitiveStyle::with_fill(style.start_color))
+                        .draw(target)?;
+                    }
+                    FieldType::Goal => {
+                        Circle::with_center(
+                            Point::new(ix * scale + scale / 2, iy * scale + scale / 2),
+                            style.scale / 2,
+                        )
+                        .into_styled(PrimitiveStyle::with_fill(style.goal_color))
+                        .draw(target)?;
+                    }
+                    FieldType::Normal => {}
+                }
+            }
+        }
+
+        // outer border
+        let width = self.size.0 * scale;
+        let height = self.size.1 * scale;
+        Line::new(Point::new(0, 0), Point::new(width, 0))
+            .into_styled(wall_style)
+            .draw(target)?;
+        Line::new(Point::new(0, 0), Point::new(0, height))
+            .into_styled(wall_style)
+            .draw(target)?;
+        Line::new(Point::new(width, 0), Point::new(width, height))
+            .into_styled(wall_style)
+            .draw(target)?;
+        Line::new(Point::new(0, height), Point::new(width, height))
+            .into_styled(wall_style)
+            .draw(target)?;
+
+        Ok(())
+    }
+}
+
 // implemented as into and not accessor because after exposing the internal graph, data integrity
 // can not be guaranteed (size, start, goal could be made invalid).
 impl From<Maze> for MazeGraph {
@@ -288,3 +695,178 @@ impl PartialEq for Maze {
 
 #[cfg(feature = "std")]
 impl Eq for Maze {}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    /// A 3x3 maze with a single serpentine passage carved from `start` to `goal`, touching every
+    /// cell exactly once, so distances and path length are trivial to hand-compute.
+    fn serpentine_3x3() -> Maze {
+        let start = Coordinates::new(0, 0);
+        let goal = Coordinates::new(2, 2);
+        let mut maze = Maze::new(3, 3, start, goal);
+
+        let cells: [(i32, i32); 9] = [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (1, 1),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+        ];
+        for pair in cells.windows(2) {
+            maze.graph
+                .add_edge(Coordinates::from(pair[0]), Coordinates::from(pair[1]), ());
+        }
+
+        maze
+    }
+
+    #[test]
+    fn solve_finds_a_contiguous_passage_connected_path() {
+        let maze = serpentine_3x3();
+
+        let path = maze.solve().expect("start and goal are connected");
+        assert_eq!(path.first(), Some(&maze.start));
+        assert_eq!(path.last(), Some(&maze.goal));
+        for pair in path.windows(2) {
+            assert!(maze.graph.contains_edge(pair[0], pair[1]));
+        }
+    }
+
+    #[test]
+    fn distances_from_agree_with_solve_path_length() {
+        let maze = serpentine_3x3();
+
+        let path = maze.solve().expect("start and goal are connected");
+        let distances = maze.distances_from(&maze.start);
+
+        assert_eq!(distances[&maze.goal] as usize, path.len() - 1);
+    }
+
+    #[test]
+    fn farthest_cell_from_start_is_the_goal_on_a_single_corridor() {
+        let maze = serpentine_3x3();
+        assert_eq!(maze.farthest_cell_from(&maze.start), maze.goal);
+    }
+
+    #[test]
+    fn solve_returns_none_when_start_and_goal_are_disconnected() {
+        let start = Coordinates::new(0, 0);
+        let goal = Coordinates::new(2, 2);
+        let mut maze = Maze::new(3, 3, start, goal);
+        // Carve a passage that never reaches the goal.
+        maze.graph.add_edge(start, Coordinates::new(1, 0), ());
+
+        assert_eq!(maze.solve(), None);
+        assert!(!maze.distances_from(&maze.start).contains_key(&maze.goal));
+    }
+
+    #[test]
+    fn to_grid_has_the_expected_dimensions() {
+        let start = Coordinates::new(0, 0);
+        let goal = Coordinates::new(1, 0);
+        let mut maze = Maze::new(2, 1, start, goal);
+        maze.graph.add_edge(start, goal, ());
+
+        let grid = maze.to_grid(2, 1);
+
+        // height = wall_thickness + n * (cell_size + wall_thickness)
+        assert_eq!(grid.len(), 1 + 1 * (2 + 1));
+        // width, same formula, for the maze's 2 columns
+        assert_eq!(grid[0].len(), 1 + 2 * (2 + 1));
+    }
+
+    #[test]
+    fn to_grid_carves_passages_and_leaves_walls_closed() {
+        let start = Coordinates::new(0, 0);
+        let goal = Coordinates::new(1, 0);
+        let mut maze = Maze::new(2, 1, start, goal);
+        maze.graph.add_edge(start, goal, ());
+
+        let grid = maze.to_grid(2, 1);
+
+        // The single wall column separating the two 2-wide floor blocks must be carved to Floor
+        // across both floor rows, since there is a passage between (0,0) and (1,0).
+        assert_eq!(grid[1][3], Tile::Floor);
+        assert_eq!(grid[2][3], Tile::Floor);
+
+        // There is no maze row above (0,0), so the whole top wall band must stay closed.
+        assert_eq!(grid[0][1], Tile::Wall);
+        assert_eq!(grid[0][2], Tile::Wall);
+
+        // Start and goal markers land in their respective floor blocks.
+        assert_eq!(grid[1][1], Tile::Start);
+        assert_eq!(grid[1][4], Tile::Goal);
+    }
+
+    #[test]
+    fn distort_point_is_a_no_op_when_amplitude_is_zero() {
+        assert_eq!(distort_point(0, 3, 4), (3, 4));
+    }
+
+    #[test]
+    fn distort_point_is_deterministic_for_shared_corners() {
+        // Two wall segments meeting at the same corner must distort identically, or the walls
+        // would visibly separate at the joint.
+        let from_one_segment = distort_point(5, 10, 20);
+        let from_another_segment = distort_point(5, 10, 20);
+        assert_eq!(from_one_segment, from_another_segment);
+    }
+
+    #[test]
+    fn to_svg_inverted_uses_configured_stroke_and_background_colors() {
+        let start = Coordinates::new(0, 0);
+        let goal = Coordinates::new(1, 0);
+        let mut maze = Maze::new(2, 1, start, goal);
+        maze.graph.add_edge(start, goal, ());
+
+        let options = SvgOptions {
+            inverted: true,
+            strokecol: "black".into(),
+            backgroundcol: "magenta".into(),
+            ..SvgOptions::default()
+        };
+
+        let svg = maze.to_svg(options).expect("svg generation should not fail");
+
+        assert!(svg.contains("fill=\"black\""));
+        assert!(svg.contains("fill=\"magenta\""));
+    }
+}
+
+#[cfg(all(test, feature = "embedded_graphics"))]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod embedded_graphics_tests {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    #[test]
+    fn draw_renders_onto_a_mock_display_without_error() {
+        let start = Coordinates::new(0, 0);
+        let goal = Coordinates::new(1, 0);
+        let mut maze = Maze::new(2, 1, start, goal);
+        maze.graph.add_edge(start, goal, ());
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_out_of_bounds_drawing(true);
+        // Adjacent wall segments share their corner pixels.
+        display.set_allow_overdraw(true);
+
+        let style = MazeDrawStyle {
+            stroke_color: BinaryColor::On,
+            stroke_width: 1,
+            scale: 8,
+            start_color: BinaryColor::On,
+            goal_color: BinaryColor::On,
+        };
+
+        maze.draw(&mut display, style)
+            .expect("drawing onto a mock display should not fail");
+    }
+}